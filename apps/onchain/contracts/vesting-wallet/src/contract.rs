@@ -0,0 +1,451 @@
+use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec};
+
+use crate::error::Error;
+use crate::events::{
+    ApprovalRecordedEvent, TokensClaimedEvent, VestingCreatedEvent, VestingRevokedEvent,
+};
+use crate::storage::{self, ApprovalGate, ScheduleKind, VestingEntry, VestingInfo, VestingSchedule};
+
+#[contract]
+pub struct VestingWalletContract;
+
+#[contractimpl]
+impl VestingWalletContract {
+    /// Creates a linear vesting schedule, pulling `amount` of `token` from
+    /// `grantor` into the contract.
+    pub fn create_vesting(
+        env: Env,
+        grantor: Address,
+        beneficiary: Address,
+        token: Address,
+        amount: i128,
+        start_time: u64,
+        duration: u64,
+        revocable: bool,
+        approvers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), Error> {
+        Self::create_vesting_with_schedule(
+            env,
+            grantor,
+            beneficiary,
+            token,
+            amount,
+            start_time,
+            duration,
+            ScheduleKind::Linear,
+            revocable,
+            approvers,
+            threshold,
+        )
+    }
+
+    /// Creates a cliff vesting schedule: nothing claimable until
+    /// `start_time + cliff_duration`; from then on, the linear amount vested
+    /// from `start_time` over `duration`.
+    pub fn create_cliff_vesting(
+        env: Env,
+        grantor: Address,
+        beneficiary: Address,
+        token: Address,
+        amount: i128,
+        start_time: u64,
+        duration: u64,
+        cliff_duration: u64,
+        revocable: bool,
+        approvers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), Error> {
+        Self::create_vesting_with_schedule(
+            env,
+            grantor,
+            beneficiary,
+            token,
+            amount,
+            start_time,
+            duration,
+            ScheduleKind::Cliff { cliff_duration },
+            revocable,
+            approvers,
+            threshold,
+        )
+    }
+
+    /// Creates a milestone vesting schedule: each tranche becomes claimable
+    /// once ledger time passes its `unlock_time`. Tranche amounts must sum
+    /// to `amount` and `unlock_time`s must be strictly increasing.
+    pub fn create_milestone_vesting(
+        env: Env,
+        grantor: Address,
+        beneficiary: Address,
+        token: Address,
+        amount: i128,
+        tranches: Vec<(u64, i128)>,
+        revocable: bool,
+        approvers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), Error> {
+        validate_tranches(&tranches, amount)?;
+        let start_time = tranches.first().map(|(t, _)| t).unwrap_or(0);
+        Self::create_vesting_with_schedule(
+            env,
+            grantor,
+            beneficiary,
+            token,
+            amount,
+            start_time,
+            0,
+            ScheduleKind::Milestone { tranches },
+            revocable,
+            approvers,
+            threshold,
+        )
+    }
+
+    fn create_vesting_with_schedule(
+        env: Env,
+        grantor: Address,
+        beneficiary: Address,
+        token: Address,
+        amount: i128,
+        start_time: u64,
+        duration: u64,
+        kind: ScheduleKind,
+        revocable: bool,
+        approvers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), Error> {
+        grantor.require_auth();
+
+        if storage::has_schedule(&env, &beneficiary) {
+            return Err(Error::AlreadyVesting);
+        }
+
+        let schedule = build_schedule(
+            &grantor, &token, amount, start_time, duration, kind, revocable, approvers, threshold,
+        )?;
+
+        token::Client::new(&env, &token).transfer(&grantor, &env.current_contract_address(), &amount);
+        storage::write_schedule(&env, &beneficiary, &schedule);
+
+        VestingCreatedEvent {
+            beneficiary,
+            schedule_kind: schedule.kind.topic(&env),
+            amount,
+            start_time,
+            duration,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Atomically registers one vesting per entry, pulling the summed
+    /// `total_amount` of `token` from `grantor` in a single transfer.
+    /// Rolls back the whole batch if any entry is invalid.
+    pub fn create_vestings(
+        env: Env,
+        grantor: Address,
+        token: Address,
+        entries: Vec<VestingEntry>,
+        total_amount: i128,
+    ) -> Result<(), Error> {
+        grantor.require_auth();
+
+        if entries.is_empty() {
+            return Err(Error::InvalidSchedule);
+        }
+
+        let mut seen = Vec::new(&env);
+        let mut schedules = Vec::new(&env);
+        let mut summed: i128 = 0;
+
+        for entry in entries.iter() {
+            if seen.contains(&entry.beneficiary) {
+                return Err(Error::DuplicateBeneficiary);
+            }
+            if storage::has_schedule(&env, &entry.beneficiary) {
+                return Err(Error::AlreadyVesting);
+            }
+            if let ScheduleKind::Milestone { tranches } = &entry.kind {
+                validate_tranches(tranches, entry.amount)?;
+            }
+
+            let schedule = build_schedule(
+                &grantor,
+                &token,
+                entry.amount,
+                entry.start_time,
+                entry.duration,
+                entry.kind.clone(),
+                entry.revocable,
+                entry.approvers.clone(),
+                entry.threshold,
+            )?;
+
+            summed += entry.amount;
+            seen.push_back(entry.beneficiary.clone());
+            schedules.push_back((entry.beneficiary.clone(), schedule));
+        }
+
+        if summed != total_amount {
+            return Err(Error::InvalidSchedule);
+        }
+
+        token::Client::new(&env, &token).transfer(
+            &grantor,
+            &env.current_contract_address(),
+            &total_amount,
+        );
+
+        for (beneficiary, schedule) in schedules.iter() {
+            storage::write_schedule(&env, &beneficiary, &schedule);
+
+            VestingCreatedEvent {
+                beneficiary: beneficiary.clone(),
+                schedule_kind: schedule.kind.topic(&env),
+                amount: schedule.amount,
+                start_time: schedule.start_time,
+                duration: schedule.duration,
+            }
+            .publish(&env);
+        }
+
+        Ok(())
+    }
+
+    /// Claims all tokens vested so far for `beneficiary`.
+    pub fn claim(env: Env, beneficiary: Address) -> Result<i128, Error> {
+        beneficiary.require_auth();
+
+        if !storage::has_schedule(&env, &beneficiary) {
+            return Err(Error::NoVesting);
+        }
+        let mut schedule = storage::read_schedule(&env, &beneficiary);
+
+        if let Some(gate) = &schedule.approval_gate {
+            if !storage::approval_gate_satisfied(&env, &beneficiary, gate) {
+                return Err(Error::AwaitingApproval);
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let vested = storage::vested_amount(&schedule, now);
+        let claimable = vested - schedule.claimed;
+        if claimable <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+
+        schedule.claimed += claimable;
+        storage::write_schedule(&env, &beneficiary, &schedule);
+
+        token::Client::new(&env, &schedule.token).transfer(
+            &env.current_contract_address(),
+            &beneficiary,
+            &claimable,
+        );
+
+        TokensClaimedEvent {
+            beneficiary,
+            amount_claimed: claimable,
+            remaining: schedule.amount - schedule.claimed,
+        }
+        .publish(&env);
+
+        Ok(claimable)
+    }
+
+    /// Revokes a revocable vesting. The grantor reclaims the unvested
+    /// remainder; the portion already vested as of now stays claimable by
+    /// the beneficiary. Further vesting stops.
+    pub fn revoke(env: Env, beneficiary: Address) -> Result<(), Error> {
+        if !storage::has_schedule(&env, &beneficiary) {
+            return Err(Error::NoVesting);
+        }
+        let mut schedule = storage::read_schedule(&env, &beneficiary);
+        schedule.grantor.require_auth();
+
+        if !schedule.revocable {
+            return Err(Error::NotRevocable);
+        }
+
+        let now = env.ledger().timestamp();
+        let vested = storage::vested_amount(&schedule, now);
+        let refunded = schedule.amount - vested;
+
+        schedule.amount = vested;
+        schedule.kind = ScheduleKind::Linear;
+        schedule.start_time = 0;
+        schedule.duration = 0;
+        schedule.revocable = false;
+        // The time-based vesting is already frozen by revocation, so the
+        // approval gate no longer serves a purpose — clear it so the
+        // retained, already-vested funds stay claimable.
+        schedule.approval_gate = None;
+        let grantor = schedule.grantor.clone();
+        storage::write_schedule(&env, &beneficiary, &schedule);
+
+        if refunded > 0 {
+            token::Client::new(&env, &schedule.token).transfer(
+                &env.current_contract_address(),
+                &grantor,
+                &refunded,
+            );
+        }
+
+        VestingRevokedEvent {
+            beneficiary,
+            vested_retained: vested - schedule.claimed,
+            refunded,
+            revoke_time: now,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Records `approver`'s sign-off on `beneficiary`'s approval-gated
+    /// vesting. Once the configured threshold of approvers has each called
+    /// this, `claim` unlocks normal time-based vesting.
+    pub fn approve(env: Env, beneficiary: Address, approver: Address) -> Result<u32, Error> {
+        approver.require_auth();
+
+        if !storage::has_schedule(&env, &beneficiary) {
+            return Err(Error::NoVesting);
+        }
+        let schedule = storage::read_schedule(&env, &beneficiary);
+        let gate = schedule.approval_gate.as_ref().ok_or(Error::NoApprovalGate)?;
+
+        if !gate.approvers.contains(&approver) {
+            return Err(Error::NotApprover);
+        }
+
+        let mut approvals = storage::read_approvals(&env, &beneficiary);
+        if approvals.contains(&approver) {
+            return Err(Error::AlreadyApproved);
+        }
+        approvals.push_back(approver.clone());
+        storage::write_approvals(&env, &beneficiary, &approvals);
+
+        let approvals_count = approvals.len();
+        ApprovalRecordedEvent {
+            beneficiary,
+            approver,
+            approvals_count,
+            threshold: gate.threshold,
+        }
+        .publish(&env);
+
+        Ok(approvals_count)
+    }
+
+    /// Returns `beneficiary`'s full stored vesting schedule without
+    /// mutating state.
+    pub fn get_schedule(env: Env, beneficiary: Address) -> Result<VestingInfo, Error> {
+        if !storage::has_schedule(&env, &beneficiary) {
+            return Err(Error::NoVesting);
+        }
+        let schedule = storage::read_schedule(&env, &beneficiary);
+        let approvals_count = storage::read_approvals(&env, &beneficiary).len();
+
+        Ok(VestingInfo {
+            grantor: schedule.grantor,
+            token: schedule.token,
+            amount: schedule.amount,
+            start_time: schedule.start_time,
+            duration: schedule.duration,
+            claimed: schedule.claimed,
+            kind: schedule.kind,
+            revocable: schedule.revocable,
+            approval_gate: schedule.approval_gate,
+            approvals_count,
+        })
+    }
+
+    /// Returns the amount `beneficiary` would be able to claim at
+    /// `at_time`, without mutating state. Returns 0 while an approval gate
+    /// is unmet, where `claim` fails with `AwaitingApproval`.
+    pub fn get_claimable(env: Env, beneficiary: Address, at_time: u64) -> Result<i128, Error> {
+        if !storage::has_schedule(&env, &beneficiary) {
+            return Err(Error::NoVesting);
+        }
+        let schedule = storage::read_schedule(&env, &beneficiary);
+
+        if let Some(gate) = &schedule.approval_gate {
+            if !storage::approval_gate_satisfied(&env, &beneficiary, gate) {
+                return Ok(0);
+            }
+        }
+
+        let vested = storage::vested_amount(&schedule, at_time);
+        Ok((vested - schedule.claimed).max(0))
+    }
+}
+
+fn build_schedule(
+    grantor: &Address,
+    token: &Address,
+    amount: i128,
+    start_time: u64,
+    duration: u64,
+    kind: ScheduleKind,
+    revocable: bool,
+    approvers: Vec<Address>,
+    threshold: u32,
+) -> Result<VestingSchedule, Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidSchedule);
+    }
+    if approvers.is_empty() {
+        if threshold > 0 {
+            return Err(Error::InvalidSchedule);
+        }
+    } else if threshold == 0 || threshold > approvers.len() {
+        return Err(Error::InvalidSchedule);
+    }
+
+    let approval_gate = if approvers.is_empty() {
+        None
+    } else {
+        Some(ApprovalGate { approvers, threshold })
+    };
+
+    Ok(VestingSchedule {
+        grantor: grantor.clone(),
+        token: token.clone(),
+        amount,
+        start_time,
+        duration,
+        claimed: 0,
+        kind,
+        revocable,
+        approval_gate,
+    })
+}
+
+fn validate_tranches(tranches: &Vec<(u64, i128)>, amount: i128) -> Result<(), Error> {
+    if tranches.is_empty() {
+        return Err(Error::InvalidSchedule);
+    }
+
+    let mut total: i128 = 0;
+    let mut prev_unlock: Option<u64> = None;
+    for (unlock_time, tranche_amount) in tranches.iter() {
+        if tranche_amount <= 0 {
+            return Err(Error::InvalidSchedule);
+        }
+        if let Some(prev) = prev_unlock {
+            if unlock_time <= prev {
+                return Err(Error::InvalidSchedule);
+            }
+        }
+        prev_unlock = Some(unlock_time);
+        total += tranche_amount;
+    }
+
+    if total != amount {
+        return Err(Error::InvalidSchedule);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyVesting = 1,
+    NoVesting = 2,
+    InvalidSchedule = 3,
+    NothingToClaim = 4,
+    NotRevocable = 5,
+    AwaitingApproval = 6,
+    NoApprovalGate = 7,
+    NotApprover = 8,
+    AlreadyApproved = 9,
+    DuplicateBeneficiary = 10,
+}
@@ -1,10 +1,12 @@
-use soroban_sdk::{contractevent, Address};
+use soroban_sdk::{contractevent, Address, Symbol};
 
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VestingCreatedEvent {
     #[topic]
     pub beneficiary: Address,
+    #[topic]
+    pub schedule_kind: Symbol,
     pub amount: i128,
     pub start_time: u64,
     pub duration: u64,
@@ -19,3 +21,24 @@ pub struct TokensClaimedEvent {
     pub remaining: i128,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingRevokedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub vested_retained: i128,
+    pub refunded: i128,
+    pub revoke_time: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalRecordedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    #[topic]
+    pub approver: Address,
+    pub approvals_count: u32,
+    pub threshold: u32,
+}
+
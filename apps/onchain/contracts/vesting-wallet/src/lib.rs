@@ -0,0 +1,10 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod events;
+mod storage;
+
+pub use contract::VestingWalletContract;
+pub use error::Error;
+pub use storage::{ApprovalGate, ScheduleKind, VestingEntry, VestingInfo};
@@ -0,0 +1,155 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+/// How a vesting's principal becomes claimable over time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ScheduleKind {
+    /// Unlocks continuously from `start_time` over `duration`.
+    Linear,
+    /// Nothing claimable until `start_time + cliff_duration`; from then on,
+    /// the linear amount vested from `start_time` over `duration`.
+    Cliff { cliff_duration: u64 },
+    /// Discrete tranches, each claimable once ledger time passes its
+    /// `unlock_time`. `unlock_time`s are strictly increasing.
+    Milestone { tranches: Vec<(u64, i128)> },
+}
+
+impl ScheduleKind {
+    /// Short tag used for the `schedule_kind` event topic.
+    pub fn topic(&self, env: &Env) -> Symbol {
+        match self {
+            ScheduleKind::Linear => Symbol::new(env, "linear"),
+            ScheduleKind::Cliff { .. } => Symbol::new(env, "cliff"),
+            ScheduleKind::Milestone { .. } => Symbol::new(env, "milestone"),
+        }
+    }
+}
+
+/// Requires `threshold` of `approvers` to each call `approve` before the
+/// vesting's normal time-based claimable amount unlocks.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalGate {
+    pub approvers: Vec<Address>,
+    pub threshold: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub grantor: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub start_time: u64,
+    pub duration: u64,
+    pub claimed: i128,
+    pub kind: ScheduleKind,
+    pub revocable: bool,
+    pub approval_gate: Option<ApprovalGate>,
+}
+
+/// Read-only snapshot of a beneficiary's vesting, returned by the
+/// contract's view functions so wallets/dashboards can query a single
+/// beneficiary's position without replaying the event log.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingInfo {
+    pub grantor: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub start_time: u64,
+    pub duration: u64,
+    pub claimed: i128,
+    pub kind: ScheduleKind,
+    pub revocable: bool,
+    pub approval_gate: Option<ApprovalGate>,
+    pub approvals_count: u32,
+}
+
+/// One beneficiary's worth of vesting parameters, used by
+/// `create_vestings` to register many schedules in a single call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingEntry {
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub start_time: u64,
+    pub duration: u64,
+    pub kind: ScheduleKind,
+    pub revocable: bool,
+    pub approvers: Vec<Address>,
+    pub threshold: u32,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Schedule(Address),
+    Approvals(Address),
+}
+
+pub fn read_approvals(env: &Env, beneficiary: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Approvals(beneficiary.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn write_approvals(env: &Env, beneficiary: &Address, approvals: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Approvals(beneficiary.clone()), approvals);
+}
+
+/// Whether the vesting's approval gate (if any) has reached its threshold.
+pub fn approval_gate_satisfied(env: &Env, beneficiary: &Address, gate: &ApprovalGate) -> bool {
+    read_approvals(env, beneficiary).len() >= gate.threshold
+}
+
+pub fn has_schedule(env: &Env, beneficiary: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Schedule(beneficiary.clone()))
+}
+
+pub fn read_schedule(env: &Env, beneficiary: &Address) -> VestingSchedule {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Schedule(beneficiary.clone()))
+        .expect("no vesting schedule for beneficiary")
+}
+
+pub fn write_schedule(env: &Env, beneficiary: &Address, schedule: &VestingSchedule) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Schedule(beneficiary.clone()), schedule);
+}
+
+/// Amount vested (claimable-or-already-claimed) as of `now`, independent of
+/// how much has actually been claimed so far.
+pub fn vested_amount(schedule: &VestingSchedule, now: u64) -> i128 {
+    match &schedule.kind {
+        ScheduleKind::Linear => linear_vested(schedule.amount, schedule.start_time, schedule.duration, now),
+        ScheduleKind::Cliff { cliff_duration } => {
+            if now < schedule.start_time.saturating_add(*cliff_duration) {
+                0
+            } else {
+                linear_vested(schedule.amount, schedule.start_time, schedule.duration, now)
+            }
+        }
+        ScheduleKind::Milestone { tranches } => tranches
+            .iter()
+            .filter(|(unlock_time, _)| now >= *unlock_time)
+            .fold(0i128, |acc, (_, amount)| acc + amount),
+    }
+}
+
+fn linear_vested(amount: i128, start_time: u64, duration: u64, now: u64) -> i128 {
+    if now < start_time {
+        0
+    } else if duration == 0 || now >= start_time.saturating_add(duration) {
+        amount
+    } else {
+        let elapsed = (now - start_time) as i128;
+        amount * elapsed / duration as i128
+    }
+}